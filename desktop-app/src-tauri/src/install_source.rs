@@ -0,0 +1,185 @@
+// Registry of the ways the backend can be installed. Each way to install
+// is its own `InstallationSource` so that adding a new one (e.g. a future
+// system package manager integration) is a matter of registering an impl
+// here rather than editing `auto_setup_on_first_run`'s branching.
+
+use std::path::Path;
+
+use async_trait::async_trait;
+use serde::Serialize;
+
+use crate::runtime;
+use crate::{install_backend, install_backend_direct};
+
+#[async_trait]
+pub trait InstallationSource: Send + Sync {
+    /// Stable identifier surfaced to the frontend.
+    fn name(&self) -> &'static str;
+
+    /// Whether this source's install already exists at `install_path`.
+    fn already_installed(&self, install_path: &Path) -> bool;
+
+    /// Whether this source is applicable on the current platform, independent
+    /// of whether it has already been installed.
+    fn could_be_installed(&self) -> bool;
+
+    async fn install(&self, window: &tauri::Window) -> Result<String, String>;
+
+    /// Starts the backend this source installed. Each source knows which
+    /// start mechanism matches what it laid down (a `.bat` launcher for the
+    /// bundled installer, the resolved interpreter for everything else).
+    async fn start(&self) -> Result<String, String>;
+}
+
+/// The bundled `setup_installer.exe`, currently Windows-only.
+pub struct BundledInstallerSource;
+
+#[async_trait]
+impl InstallationSource for BundledInstallerSource {
+    fn name(&self) -> &'static str {
+        "bundled-installer"
+    }
+
+    fn already_installed(&self, install_path: &Path) -> bool {
+        install_path.join("start_backend.bat").exists()
+    }
+
+    fn could_be_installed(&self) -> bool {
+        // Windows-only, and only actually usable if the installer exe this
+        // source runs is where `install()` expects to find it.
+        cfg!(windows) && crate::find_bundled_installer().is_some()
+    }
+
+    async fn install(&self, window: &tauri::Window) -> Result<String, String> {
+        install_backend(window.clone()).await
+    }
+
+    async fn start(&self) -> Result<String, String> {
+        crate::start_backend().await
+    }
+}
+
+/// Installs requirements with `pip` against whatever interpreter
+/// `discover_python_interpreters` selects on the host.
+pub struct DirectPipSource;
+
+#[async_trait]
+impl InstallationSource for DirectPipSource {
+    fn name(&self) -> &'static str {
+        "direct-pip"
+    }
+
+    fn already_installed(&self, install_path: &Path) -> bool {
+        // `requirements.txt` is written before pip runs, so its mere
+        // existence doesn't mean the install succeeded - check the version
+        // marker, which is only written after a successful install.
+        crate::lifecycle::installed_version(install_path).is_some()
+    }
+
+    fn could_be_installed(&self) -> bool {
+        // Don't claim readiness on a machine with no Python at all - pip
+        // would just fail immediately inside `install()`.
+        crate::interpreter::has_any_interpreter()
+    }
+
+    async fn install(&self, window: &tauri::Window) -> Result<String, String> {
+        install_backend_direct(window.clone()).await
+    }
+
+    async fn start(&self) -> Result<String, String> {
+        crate::start_backend_direct().await
+    }
+}
+
+/// Downloads a self-contained CPython build, requiring nothing pre-installed,
+/// then pip-installs the backend's requirements against it.
+pub struct StandaloneRuntimeSource;
+
+#[async_trait]
+impl InstallationSource for StandaloneRuntimeSource {
+    fn name(&self) -> &'static str {
+        "standalone-runtime"
+    }
+
+    fn already_installed(&self, install_path: &Path) -> bool {
+        // `runtime_installed` alone only means the interpreter archive was
+        // extracted; `install()` below still has to pip-install requirements
+        // before the backend can run, so gate on the version marker that's
+        // only written once that finishes too, same as `DirectPipSource`.
+        runtime::runtime_installed(install_path)
+            && crate::lifecycle::installed_version(install_path).is_some()
+    }
+
+    fn could_be_installed(&self) -> bool {
+        true
+    }
+
+    async fn install(&self, window: &tauri::Window) -> Result<String, String> {
+        runtime::download_python_runtime().await?;
+
+        let app_data = dirs::data_local_dir().ok_or("Could not find local app data directory")?;
+        let install_path = app_data.join("VisualPIIStripper");
+        let python_path = runtime::interpreter_path(&install_path);
+
+        crate::install_requirements(&python_path, &install_path, false, window).await?;
+
+        if let Err(e) = crate::lifecycle::write_version_marker(&install_path) {
+            println!("⚠️  Failed to write version marker: {}", e);
+        }
+
+        Ok("Standalone Python runtime and backend packages installed.".to_string())
+    }
+
+    async fn start(&self) -> Result<String, String> {
+        crate::start_backend_direct().await
+    }
+}
+
+pub fn all_sources() -> Vec<Box<dyn InstallationSource>> {
+    vec![
+        Box::new(BundledInstallerSource),
+        Box::new(DirectPipSource),
+        Box::new(StandaloneRuntimeSource),
+    ]
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InstallOption {
+    pub name: String,
+    pub already_installed: bool,
+    pub supported: bool,
+    pub ready_to_install: bool,
+}
+
+#[tauri::command]
+pub async fn list_install_options() -> Result<Vec<InstallOption>, String> {
+    let app_data = dirs::data_local_dir().ok_or("Could not find local app data directory")?;
+    let install_path = app_data.join("VisualPIIStripper");
+
+    let options = all_sources()
+        .into_iter()
+        .map(|source| {
+            let already_installed = source.already_installed(&install_path);
+            let supported = source.could_be_installed();
+            InstallOption {
+                name: source.name().to_string(),
+                already_installed,
+                supported,
+                ready_to_install: supported && !already_installed,
+            }
+        })
+        .collect();
+
+    Ok(options)
+}
+
+/// All sources that are supported on this platform, not already installed,
+/// and ready to install, in the order they should be tried. Callers should
+/// fall through to the next one if a given source's `install()` fails,
+/// rather than giving up after the first.
+pub fn ready_sources(install_path: &Path) -> Vec<Box<dyn InstallationSource>> {
+    all_sources()
+        .into_iter()
+        .filter(|source| source.could_be_installed() && !source.already_installed(install_path))
+        .collect()
+}
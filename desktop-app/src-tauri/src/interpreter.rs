@@ -0,0 +1,232 @@
+// Discovery of usable Python interpreters already present on the host,
+// used before falling back to the standalone runtime download in
+// `runtime.rs`. Mirrors the lookup order common Python tooling (pyenv,
+// virtualenvwrapper, etc.) already uses.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde::Serialize;
+
+use crate::runtime;
+
+/// Lowest interpreter version the backend is tested against.
+const MIN_PYTHON_VERSION: (u32, u32, u32) = (3, 9, 0);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PythonInterpreter {
+    pub path: String,
+    pub version: String,
+    pub source: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InterpreterDiscovery {
+    pub candidates: Vec<PythonInterpreter>,
+    pub selected: Option<PythonInterpreter>,
+}
+
+fn parse_version(output: &str) -> Option<(u32, u32, u32)> {
+    let version_str = output.trim().strip_prefix("Python ")?;
+    let mut parts = version_str.split('.');
+    let major: u32 = parts.next()?.parse().ok()?;
+    let minor: u32 = parts.next()?.parse().ok()?;
+    let patch: u32 = parts.next().unwrap_or("0").parse().unwrap_or(0);
+    Some((major, minor, patch))
+}
+
+fn meets_minimum(version: (u32, u32, u32)) -> bool {
+    version >= MIN_PYTHON_VERSION
+}
+
+/// Runs `<candidate> --version` with the console hidden on Windows and
+/// returns the parsed version if it is usable.
+fn probe(candidate: &Path) -> Option<(String, (u32, u32, u32))> {
+    let mut cmd = runtime::hidden_console_command(candidate);
+    let output = cmd.arg("--version").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    // Python <= 3.3 prints the version to stderr instead of stdout.
+    let text = if !output.stdout.is_empty() {
+        String::from_utf8_lossy(&output.stdout).to_string()
+    } else {
+        String::from_utf8_lossy(&output.stderr).to_string()
+    };
+
+    let parsed = parse_version(&text)?;
+    Some((format!("{}.{}.{}", parsed.0, parsed.1, parsed.2), parsed))
+}
+
+fn candidate_from(path: PathBuf, source: &str) -> Option<PythonInterpreter> {
+    let (version, parsed) = probe(&path)?;
+    if !meets_minimum(parsed) {
+        println!("⚠️  Skipping {:?}: version {} is below the minimum of 3.9", path, version);
+        return None;
+    }
+    Some(PythonInterpreter {
+        path: path.to_string_lossy().to_string(),
+        version,
+        source: source.to_string(),
+    })
+}
+
+fn virtualenv_candidate() -> Option<PythonInterpreter> {
+    let venv = std::env::var("VIRTUAL_ENV").ok()?;
+    let venv_path = PathBuf::from(venv);
+    let python_path = if cfg!(windows) {
+        venv_path.join("Scripts").join("python.exe")
+    } else {
+        venv_path.join("bin").join("python")
+    };
+    candidate_from(python_path, "VIRTUAL_ENV")
+}
+
+fn pyenv_candidate() -> Option<PythonInterpreter> {
+    let root_output = Command::new("pyenv").arg("root").output().ok()?;
+    if !root_output.status.success() {
+        return None;
+    }
+    let root = String::from_utf8_lossy(&root_output.stdout).trim().to_string();
+
+    let version_output = Command::new("pyenv").arg("version-name").output().ok()?;
+    if !version_output.status.success() {
+        return None;
+    }
+    let version_name = String::from_utf8_lossy(&version_output.stdout).trim().to_string();
+
+    let python_path = PathBuf::from(root)
+        .join("versions")
+        .join(&version_name)
+        .join("bin")
+        .join("python");
+    candidate_from(python_path, &format!("pyenv ({})", version_name))
+}
+
+/// Resolves `name` to an absolute path by walking `PATH`, mirroring what the
+/// shell would invoke - so callers get a path pinned to what was actually
+/// found rather than a bare name that could resolve differently later if
+/// `PATH` changes.
+fn resolve_on_path(name: &str) -> Option<PathBuf> {
+    let path_var = std::env::var_os("PATH")?;
+    let exe_name = if cfg!(windows) {
+        format!("{}.exe", name)
+    } else {
+        name.to_string()
+    };
+
+    std::env::split_paths(&path_var)
+        .map(|dir| dir.join(&exe_name))
+        .find(|candidate| candidate.is_file())
+}
+
+fn path_candidate(name: &str) -> Option<PythonInterpreter> {
+    let resolved = resolve_on_path(name)?;
+    candidate_from(resolved, "PATH")
+}
+
+/// The actual discovery work, factored out so it can be driven both by the
+/// `discover_python_interpreters` command and by a quick synchronous
+/// viability check (`has_any_interpreter`) - none of the probing below
+/// actually awaits anything, so there's nothing async about the logic itself.
+fn discover() -> InterpreterDiscovery {
+    let mut candidates = Vec::new();
+    candidates.extend(virtualenv_candidate());
+    candidates.extend(pyenv_candidate());
+    candidates.extend(path_candidate("python"));
+    candidates.extend(path_candidate("python3"));
+
+    let selected = candidates.first().cloned();
+    InterpreterDiscovery { candidates, selected }
+}
+
+#[tauri::command]
+pub async fn discover_python_interpreters() -> Result<InterpreterDiscovery, String> {
+    println!("🔍 Discovering Python interpreters...");
+
+    let discovery = discover();
+    if let Some(selected) = &discovery.selected {
+        println!("✅ Selected interpreter: {} ({})", selected.path, selected.version);
+    } else {
+        println!("❌ No usable Python interpreter found on this system");
+    }
+
+    Ok(discovery)
+}
+
+/// Whether a usable (>= 3.9) Python interpreter can be found on the host at
+/// all, without caring which one - used by `DirectPipSource::could_be_installed`
+/// so it doesn't report itself as ready on machines with no Python present.
+pub fn has_any_interpreter() -> bool {
+    discover().selected.is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_version_reads_major_minor_patch() {
+        assert_eq!(parse_version("Python 3.9.7"), Some((3, 9, 7)));
+    }
+
+    #[test]
+    fn parse_version_defaults_missing_patch_to_zero() {
+        assert_eq!(parse_version("Python 3.9"), Some((3, 9, 0)));
+    }
+
+    #[test]
+    fn parse_version_rejects_missing_prefix() {
+        assert_eq!(parse_version("3.9.7"), None);
+    }
+
+    #[test]
+    fn parse_version_rejects_garbage() {
+        assert_eq!(parse_version("Python"), None);
+    }
+
+    #[test]
+    fn meets_minimum_accepts_newer_and_equal_versions() {
+        assert!(meets_minimum((3, 9, 0)));
+        assert!(meets_minimum((3, 12, 1)));
+    }
+
+    #[test]
+    fn meets_minimum_rejects_older_versions() {
+        assert!(!meets_minimum((3, 8, 10)));
+        assert!(!meets_minimum((2, 7, 18)));
+    }
+}
+
+/// Picks the interpreter install/start flows should hand off to: the
+/// already-installed standalone runtime if present, otherwise the best
+/// discovered system interpreter.
+pub async fn resolve_interpreter(install_path: &Path) -> Result<PathBuf, String> {
+    if runtime::runtime_installed(install_path) {
+        return Ok(runtime::interpreter_path(install_path));
+    }
+
+    let discovery = discover_python_interpreters().await?;
+    discovery
+        .selected
+        .map(|c| PathBuf::from(c.path))
+        .ok_or_else(|| "No Python interpreter (>= 3.9) found on this system".to_string())
+}
+
+/// Same resolution as `resolve_interpreter`, plus whether `pip install` needs
+/// `--user` against it - true for a bare system interpreter, false for the
+/// standalone runtime (which isn't shared with anything else on the host) or
+/// an already-active virtualenv (which rejects `--user` outright).
+pub async fn resolve_interpreter_for_install(install_path: &Path) -> Result<(PathBuf, bool), String> {
+    if runtime::runtime_installed(install_path) {
+        return Ok((runtime::interpreter_path(install_path), false));
+    }
+
+    let discovery = discover_python_interpreters().await?;
+    let selected = discovery
+        .selected
+        .ok_or_else(|| "No Python interpreter (>= 3.9) found on this system".to_string())?;
+    let use_user_flag = selected.source != "VIRTUAL_ENV";
+    Ok((PathBuf::from(selected.path), use_user_flag))
+}
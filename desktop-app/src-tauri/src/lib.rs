@@ -8,6 +8,11 @@ use tauri::Emitter;
 #[cfg(windows)]
 use std::os::windows::process::CommandExt;
 
+mod runtime;
+mod interpreter;
+mod install_source;
+mod lifecycle;
+
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
 #[tauri::command]
 fn greet(name: &str) -> String {
@@ -15,87 +20,78 @@ fn greet(name: &str) -> String {
 }
 
 #[tauri::command]
-async fn check_backend_installed() -> Result<bool, String> {
+async fn check_backend_installed() -> Result<lifecycle::BackendInstallStatus, String> {
     println!("🔍 Checking if backend is installed...");
-    
+
     let app_data = dirs::data_local_dir()
         .ok_or("Could not find local app data directory")?;
     let install_path = app_data.join("VisualPIIStripper");
     let startup_script = install_path.join("start_backend.bat");
-    
+
     println!("📁 Install path: {:?}", install_path);
     println!("📜 Startup script: {:?}", startup_script);
     println!("✅ start_backend.bat exists: {}", startup_script.exists());
-    
-    Ok(startup_script.exists())
+
+    let installed = startup_script.exists()
+        || install_source::all_sources()
+            .iter()
+            .any(|source| source.already_installed(&install_path));
+
+    let version = lifecycle::installed_version(&install_path);
+    let upgrade_available = installed
+        && version
+            .as_deref()
+            .map(|v| v != lifecycle::CURRENT_BACKEND_VERSION)
+            .unwrap_or(true);
+
+    Ok(lifecycle::BackendInstallStatus {
+        installed,
+        version,
+        upgrade_available,
+    })
+}
+
+/// Looks for `setup_installer.exe` next to the running binary - first under
+/// a bundled `resources/` directory, then directly alongside the exe. Shared
+/// between `install_backend` (which needs the path to run it) and
+/// `BundledInstallerSource::could_be_installed` (which only needs to know
+/// whether it's there).
+fn find_bundled_installer() -> Option<std::path::PathBuf> {
+    let exe_path = std::env::current_exe().ok()?;
+    let exe_dir = exe_path.parent()?;
+
+    let bundled_installer = exe_dir.join("resources").join("setup_installer.exe");
+    if bundled_installer.exists() {
+        return Some(bundled_installer);
+    }
+
+    let local_installer = exe_dir.join("setup_installer.exe");
+    if local_installer.exists() {
+        return Some(local_installer);
+    }
+
+    None
 }
 
 #[tauri::command]
-async fn install_backend() -> Result<String, String> {
+async fn install_backend(window: tauri::Window) -> Result<String, String> {
     println!("🔧 Starting backend installation...");
-    
+
     let app_data = dirs::data_local_dir()
         .ok_or("Could not find local app data directory")?;
     let install_path = app_data.join("VisualPIIStripper");
-    
+
     // Check if already installed
     if install_path.exists() {
         println!("✅ Backend appears to be already installed at: {:?}", install_path);
         return Ok("Backend installation found. If you're having issues, please run the setup_installer.exe manually.".to_string());
     }
-    
-    // Look for setup_installer.exe in bundled resources first, then exe directory
-    let exe_path = std::env::current_exe()
-        .map_err(|e| format!("Could not get current exe path: {}", e))?;
-    println!("📍 Current exe path: {:?}", exe_path);
-    
-    let exe_dir = exe_path
-        .parent()
-        .ok_or("Could not get parent directory")?;
-    println!("📁 Exe directory: {:?}", exe_dir);
-    
-    // First try bundled resources
-    let resources_dir = exe_dir.join("resources");
-    let bundled_installer = resources_dir.join("setup_installer.exe");
-    println!("🔍 Looking for bundled installer at: {:?} (exists: {})", bundled_installer, bundled_installer.exists());
-    
-    // Then try exe directory
-    let local_installer = exe_dir.join("setup_installer.exe");
-    println!("🔍 Looking for local installer at: {:?} (exists: {})", local_installer, local_installer.exists());
-    
-    // Use whichever one exists
-    let installer_exe = if bundled_installer.exists() {
-        bundled_installer
-    } else if local_installer.exists() {
-        local_installer
-    } else {
-        // List all files in the exe directory for debugging
-        println!("📂 Files in exe directory:");
-        if let Ok(entries) = fs::read_dir(exe_dir) {
-            for entry in entries {
-                if let Ok(entry) = entry {
-                    println!("  - {:?}", entry.file_name());
-                }
-            }
-        }
-        
-        // Check resources directory too
-        if resources_dir.exists() {
-            println!("📂 Files in resources directory:");
-            if let Ok(entries) = fs::read_dir(&resources_dir) {
-                for entry in entries {
-                    if let Ok(entry) = entry {
-                        println!("  - {:?}", entry.file_name());
-                    }
-                }
-            }
-        }
-        
-        return Err("setup_installer.exe not found in bundled resources or exe directory".to_string());
-    };
-    
+
+    let installer_exe = find_bundled_installer()
+        .ok_or("setup_installer.exe not found in bundled resources or exe directory")?;
+
     println!("✅ Using installer: {:?}", installer_exe);
-    
+
     // Found the installer, run it automatically
     println!("🚀 Running setup_installer.exe...");
     let mut cmd = Command::new(&installer_exe);
@@ -106,54 +102,43 @@ async fn install_backend() -> Result<String, String> {
     #[cfg(windows)]
     cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW flag
     
-    let output = cmd.output()
+    let mut child = cmd.spawn()
         .map_err(|e| format!("Failed to run installer: {}", e))?;
-    
-    println!("📤 Installer exit code: {:?}", output.status.code());
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let stderr = String::from_utf8_lossy(&output.stderr);
-    println!("📤 Installer stdout: {}", stdout);
-    println!("📤 Installer stderr: {}", stderr);
-    
-    if output.status.success() {
-        Ok(format!("Installation completed successfully!\n{}", stdout))
+
+    stream_install_output(&window, child.stdout.take(), child.stderr.take());
+
+    let status = child.wait()
+        .map_err(|e| format!("Failed to run installer: {}", e))?;
+
+    println!("📤 Installer exit code: {:?}", status.code());
+
+    if status.success() {
+        if let Err(e) = lifecycle::write_version_marker(&install_path) {
+            println!("⚠️  Failed to write version marker: {}", e);
+        }
+        Ok("Installation completed successfully!".to_string())
     } else {
-        Err(format!("Installation failed:\n{}", stderr))
+        Err(format!("Installation failed with exit code: {:?}", status.code()))
     }
 }
 
-#[tauri::command]
-async fn install_backend_direct() -> Result<String, String> {
-    println!("🐍 Attempting direct backend installation using system Python...");
-    
-    // Check if Python is available on the system
-    let mut python_cmd = Command::new("python");
-    python_cmd.args(["--version"]);
-    
-    // Hide console window on Windows
-    #[cfg(windows)]
-    python_cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW flag
-    
-    let python_check = python_cmd.output();
-    
-    if python_check.is_err() {
-        return Err(format!(
-            "Python not found on system. Please either:\n\
-            1. Install Python from python.org, or\n\
-            2. Place setup_installer.exe in the same directory as this application\n\
-            3. Restart this application after installation"
-        ));
-    }
-    
-    let app_data = dirs::data_local_dir()
-        .ok_or("Could not find local app data directory")?;
-    let install_path = app_data.join("VisualPIIStripper");
+/// Writes `requirements.txt` under `install_path/backend` and pip-installs it
+/// with `python_path`, streaming pip's output over `install-log` and parsed
+/// progress over `install-progress`. Shared so `StandaloneRuntimeSource` can run the identical
+/// package install against the downloaded runtime instead of a discovered
+/// system interpreter.
+async fn install_requirements(
+    python_path: &Path,
+    install_path: &Path,
+    use_user_flag: bool,
+    window: &tauri::Window,
+) -> Result<(), String> {
     let backend_dir = install_path.join("backend");
-    
+
     // Create directories
     fs::create_dir_all(&backend_dir)
         .map_err(|e| format!("Failed to create backend directory: {}", e))?;
-    
+
     // Create a minimal requirements.txt
     let requirements = r#"flask==2.3.3
 flask-cors==4.0.0
@@ -167,34 +152,192 @@ torch==2.1.1
 insightface==0.7.3
 onnxruntime==1.16.3
 "#;
-    
+
     let requirements_path = backend_dir.join("requirements.txt");
     fs::write(&requirements_path, requirements)
         .map_err(|e| format!("Failed to write requirements.txt: {}", e))?;
-    
-    // Install packages using system Python
+    let total_steps = requirements.lines().filter(|l| !l.trim().is_empty()).count();
+
+    // `--user` is rejected by pip inside an active virtualenv ("user
+    // site-packages are not visible"), so only add it when asked to.
     println!("📦 Installing Python packages...");
-    let mut pip_cmd = Command::new("python");
-    pip_cmd.args(["-m", "pip", "install", "-r", requirements_path.to_str().unwrap(), "--user"])
+    let mut pip_args = vec!["-m", "pip", "install", "-r", requirements_path.to_str().unwrap()];
+    if use_user_flag {
+        pip_args.push("--user");
+    }
+
+    let mut pip_cmd = Command::new(python_path);
+    pip_cmd.args(&pip_args)
         .stdout(Stdio::piped())
         .stderr(Stdio::piped());
-    
+
     // Hide console window on Windows
     #[cfg(windows)]
     pip_cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW flag
-    
-    let pip_install = pip_cmd
-        .output()
+
+    let mut child = pip_cmd.spawn()
         .map_err(|e| format!("Failed to run pip install: {}", e))?;
-    
-    if !pip_install.status.success() {
-        let stderr = String::from_utf8_lossy(&pip_install.stderr);
-        return Err(format!("Failed to install Python packages:\n{}", stderr));
+
+    if let Some(stdout) = child.stdout.take() {
+        let window_clone = window.clone();
+        thread::spawn(move || {
+            let reader = BufReader::new(stdout);
+            let mut step = 0usize;
+            let mut last_package = String::new();
+            for line in reader.lines() {
+                if let Ok(line) = line {
+                    println!("[pip] {}", line);
+                    let _ = window_clone.emit("install-log", format!("[STDOUT] {}", line));
+                    if let Some(progress) = parse_pip_progress(&line, total_steps, &mut step, &mut last_package) {
+                        let _ = window_clone.emit("install-progress", progress);
+                    }
+                }
+            }
+        });
     }
-    
+
+    if let Some(stderr) = child.stderr.take() {
+        let window_clone = window.clone();
+        thread::spawn(move || {
+            let reader = BufReader::new(stderr);
+            for line in reader.lines() {
+                if let Ok(line) = line {
+                    println!("[pip] {}", line);
+                    let _ = window_clone.emit("install-log", format!("[STDERR] {}", line));
+                }
+            }
+        });
+    }
+
+    let status = child.wait()
+        .map_err(|e| format!("Failed to run pip install: {}", e))?;
+
+    if !status.success() {
+        return Err(format!("Failed to install Python packages (exit code: {:?})", status.code()));
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+async fn install_backend_direct(window: tauri::Window) -> Result<String, String> {
+    println!("🐍 Attempting direct backend installation using a discovered Python interpreter...");
+
+    let discovery = interpreter::discover_python_interpreters().await?;
+    let python = discovery.selected.ok_or_else(|| {
+        "No Python 3.9+ interpreter found. Please either:\n\
+        1. Install Python from python.org, or\n\
+        2. Place setup_installer.exe in the same directory as this application\n\
+        3. Restart this application after installation"
+            .to_string()
+    })?;
+    println!("🐍 Using interpreter: {} ({})", python.path, python.version);
+
+    let app_data = dirs::data_local_dir()
+        .ok_or("Could not find local app data directory")?;
+    let install_path = app_data.join("VisualPIIStripper");
+
+    install_requirements(
+        Path::new(&python.path),
+        &install_path,
+        python.source != "VIRTUAL_ENV",
+        &window,
+    )
+    .await?;
+
+    if let Err(e) = lifecycle::write_version_marker(&install_path) {
+        println!("⚠️  Failed to write version marker: {}", e);
+    }
+
     Ok("Basic backend installation completed using system Python. Some features may require manual setup.".to_string())
 }
 
+/// Pip package download/collection progress, emitted over `install-progress`
+/// so the frontend can render a real progress bar during long installs.
+#[derive(Debug, Clone, serde::Serialize)]
+struct PipProgress {
+    step: usize,
+    total: usize,
+    package: String,
+    message: String,
+}
+
+/// Parses a line of pip output for `Collecting <package>` (advances `step`
+/// and records `last_package`) or `Downloading ... (x.x MB)` (reports the
+/// download size against the package most recently collected).
+fn parse_pip_progress(
+    line: &str,
+    total: usize,
+    step: &mut usize,
+    last_package: &mut String,
+) -> Option<PipProgress> {
+    if let Some(rest) = line.trim().strip_prefix("Collecting ") {
+        *step += 1;
+        let package = rest
+            .split(|c: char| c == ' ' || c == '=' || c == '<' || c == '>' || c == '!' || c == ';')
+            .next()
+            .unwrap_or(rest)
+            .to_string();
+        *last_package = package.clone();
+        return Some(PipProgress {
+            step: *step,
+            total,
+            package,
+            message: line.trim().to_string(),
+        });
+    }
+
+    if line.trim().starts_with("Downloading ") {
+        return Some(PipProgress {
+            step: *step,
+            total,
+            package: last_package.clone(),
+            message: line.trim().to_string(),
+        });
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collecting_advances_step_and_records_package() {
+        let mut step = 0;
+        let mut last_package = String::new();
+        let progress = parse_pip_progress("Collecting flask==2.3.3", 5, &mut step, &mut last_package)
+            .expect("Collecting line should produce progress");
+        assert_eq!(progress.step, 1);
+        assert_eq!(progress.package, "flask");
+        assert_eq!(last_package, "flask");
+    }
+
+    #[test]
+    fn downloading_carries_over_last_collected_package() {
+        let mut step = 0;
+        let mut last_package = String::new();
+        parse_pip_progress("Collecting torch==2.1.1", 5, &mut step, &mut last_package);
+        let progress = parse_pip_progress(
+            "  Downloading torch-2.1.1-cp311-cp311-win_amd64.whl (192.3 MB)",
+            5,
+            &mut step,
+            &mut last_package,
+        )
+        .expect("Downloading line should produce progress");
+        assert_eq!(progress.package, "torch");
+        assert_eq!(progress.step, 1);
+    }
+
+    #[test]
+    fn unrelated_lines_are_ignored() {
+        let mut step = 0;
+        let mut last_package = String::new();
+        assert!(parse_pip_progress("Requirement already satisfied: pip", 5, &mut step, &mut last_package).is_none());
+    }
+}
+
 // Helper function to recursively copy directories
 fn copy_dir_recursive(src: &Path, dst: &Path) -> std::io::Result<()> {
     if !dst.exists() {
@@ -212,10 +355,99 @@ fn copy_dir_recursive(src: &Path, dst: &Path) -> std::io::Result<()> {
             fs::copy(&src_path, &dst_path)?;
         }
     }
-    
+
+    Ok(())
+}
+
+// Helper function to recursively remove a directory, emitting each removed
+// path over the `uninstall-progress` window event so the UI can show progress.
+fn remove_dir_recursive_with_progress(window: &tauri::Window, dir: &Path) -> std::io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+
+        if path.is_dir() {
+            remove_dir_recursive_with_progress(window, &path)?;
+        } else {
+            fs::remove_file(&path)?;
+            let _ = window.emit("uninstall-progress", path.to_string_lossy().to_string());
+        }
+    }
+
+    fs::remove_dir(dir)?;
+    let _ = window.emit("uninstall-progress", dir.to_string_lossy().to_string());
     Ok(())
 }
 
+// Streams a child process's stdout/stderr line-by-line over the
+// `install-log` window event so the UI has feedback during long-running
+// installs instead of just a spinner.
+fn stream_install_output(
+    window: &tauri::Window,
+    stdout: Option<std::process::ChildStdout>,
+    stderr: Option<std::process::ChildStderr>,
+) {
+    if let Some(stdout) = stdout {
+        let window_clone = window.clone();
+        thread::spawn(move || {
+            let reader = BufReader::new(stdout);
+            for line in reader.lines() {
+                if let Ok(line) = line {
+                    println!("[install] {}", line);
+                    let _ = window_clone.emit("install-log", format!("[STDOUT] {}", line));
+                }
+            }
+        });
+    }
+
+    if let Some(stderr) = stderr {
+        let window_clone = window.clone();
+        thread::spawn(move || {
+            let reader = BufReader::new(stderr);
+            for line in reader.lines() {
+                if let Ok(line) = line {
+                    println!("[install] {}", line);
+                    let _ = window_clone.emit("install-log", format!("[STDERR] {}", line));
+                }
+            }
+        });
+    }
+}
+
+// Best-effort stop of any backend process started from this install. There is
+// no PID tracked across app restarts, so we target processes running the
+// install's app.py instead.
+fn stop_running_backend(install_path: &Path) {
+    let app_py = install_path.join("backend").join("app.py");
+
+    #[cfg(windows)]
+    {
+        // Find PIDs whose command line mentions this install's app.py, then
+        // kill only those - a blanket `taskkill /IM python.exe` would also
+        // take down unrelated venvs, notebooks, or scripts on the machine.
+        let filter = format!("CommandLine like '%{}%'", app_py.to_string_lossy().replace('\\', "\\\\"));
+        if let Ok(output) = Command::new("wmic")
+            .args(["process", "where", &filter, "get", "ProcessId"])
+            .output()
+        {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            for line in stdout.lines().skip(1) {
+                let pid = line.trim();
+                if !pid.is_empty() {
+                    let _ = Command::new("taskkill").args(["/F", "/PID", pid]).output();
+                }
+            }
+        }
+    }
+
+    #[cfg(not(windows))]
+    {
+        let _ = Command::new("pkill")
+            .args(["-f", &app_py.to_string_lossy()])
+            .output();
+    }
+}
+
 #[tauri::command]
 async fn start_backend() -> Result<String, String> {
     println!("🚀 Starting backend using start_backend.bat...");
@@ -234,9 +466,14 @@ async fn start_backend() -> Result<String, String> {
     
     // Start the backend using the start_backend.bat file in background
     println!("🚀 Executing start_backend.bat...");
-    let _child = Command::new("cmd")
-        .args(["/C", startup_script.to_str().unwrap()])
-        .creation_flags(0x08000000) // CREATE_NO_WINDOW flag
+    let mut cmd = Command::new("cmd");
+    cmd.args(["/C", startup_script.to_str().unwrap()]);
+
+    // Hide console window on Windows
+    #[cfg(windows)]
+    cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW flag
+
+    let _child = cmd
         .spawn()
         .map_err(|e| format!("Failed to start backend: {}", e))?;
     
@@ -251,19 +488,14 @@ async fn start_backend_with_logs(window: tauri::Window) -> Result<String, String
         .ok_or("Could not find local app data directory")?;
     let install_path = app_data.join("VisualPIIStripper");
     let backend_dir = install_path.join("backend");
-    let python_runtime_dir = install_path.join("python-runtime");
-    let python_exe = python_runtime_dir.join("python.exe");
+    let python_exe = interpreter::resolve_interpreter(&install_path).await?;
     let app_py = backend_dir.join("app.py");
-    
+
     println!("📁 Install path: {:?}", install_path);
     println!("📁 Backend dir: {:?}", backend_dir);
     println!("🐍 Python exe: {:?} (exists: {})", python_exe, python_exe.exists());
     println!("📄 App.py: {:?} (exists: {})", app_py, app_py.exists());
-    
-    if !python_exe.exists() {
-        return Err("Python runtime not found in installation".to_string());
-    }
-    
+
     if !app_py.exists() {
         return Err("Backend app.py not found in installation".to_string());
     }
@@ -332,21 +564,16 @@ async fn start_backend_direct() -> Result<String, String> {
         .ok_or("Could not find local app data directory")?;
     let install_path = app_data.join("VisualPIIStripper");
     let backend_dir = install_path.join("backend");
-    let python_runtime_dir = install_path.join("python-runtime");
-    let python_exe = python_runtime_dir.join("python.exe");
+    let python_exe = interpreter::resolve_interpreter(&install_path).await?;
     let app_py = backend_dir.join("app.py");
     let venv_site_packages = install_path.join("venv").join("Lib").join("site-packages");
-    
+
     println!("📁 Install path: {:?}", install_path);
     println!("📁 Backend dir: {:?}", backend_dir);
     println!("🐍 Python exe: {:?} (exists: {})", python_exe, python_exe.exists());
     println!("📄 App.py: {:?} (exists: {})", app_py, app_py.exists());
     println!("📦 Venv site-packages: {:?} (exists: {})", venv_site_packages, venv_site_packages.exists());
-    
-    if !python_exe.exists() {
-        return Err(format!("Python runtime not found at: {:?}", python_exe));
-    }
-    
+
     if !app_py.exists() {
         return Err(format!("Backend not installed or app.py not found at: {:?}", app_py));
     }
@@ -395,6 +622,52 @@ async fn check_backend_running() -> Result<bool, String> {
     }
 }
 
+/// Progress reported on each readiness poll over `backend-ready-progress`.
+#[derive(Debug, Clone, serde::Serialize)]
+struct BackendReadyProgress {
+    attempt: u32,
+    elapsed_ms: u64,
+}
+
+/// Polls `http://localhost:8000/` with exponential backoff (starting at
+/// 250ms, capped at 3s) until a 2xx response is seen or `timeout_ms` elapses.
+/// Right after `start_backend*`, the Flask server and PaddleOCR models are
+/// still warming up, so a single probe (`check_backend_running`) flickers
+/// between "not running" and ready.
+#[tauri::command]
+async fn wait_for_backend_ready(window: tauri::Window, timeout_ms: u64) -> Result<bool, String> {
+    println!("⏳ Waiting for backend to become ready (timeout: {}ms)...", timeout_ms);
+
+    let client = reqwest::Client::new();
+    let start = std::time::Instant::now();
+    let mut delay_ms: u64 = 250;
+    const MAX_DELAY_MS: u64 = 3000;
+    let mut attempt: u32 = 0;
+
+    loop {
+        attempt += 1;
+        let elapsed_ms = start.elapsed().as_millis() as u64;
+        let _ = window.emit("backend-ready-progress", BackendReadyProgress { attempt, elapsed_ms });
+
+        if let Ok(response) = client.get("http://localhost:8000/").send().await {
+            if response.status().is_success() {
+                println!("✅ Backend responded after {} attempt(s)", attempt);
+                return Ok(true);
+            }
+        }
+
+        let elapsed_ms = start.elapsed().as_millis() as u64;
+        if elapsed_ms >= timeout_ms {
+            println!("❌ Backend did not become ready within {}ms", timeout_ms);
+            return Ok(false);
+        }
+
+        let wait_ms = delay_ms.min(timeout_ms - elapsed_ms);
+        tokio::time::sleep(std::time::Duration::from_millis(wait_ms)).await;
+        delay_ms = (delay_ms * 2).min(MAX_DELAY_MS);
+    }
+}
+
 #[tauri::command]
 async fn test_resources() -> Result<String, String> {
     println!("🧪 Testing resource access...");
@@ -451,41 +724,68 @@ async fn test_resources() -> Result<String, String> {
     Ok(result)
 }
 
+/// Runs one installation source end-to-end: install, start using whichever
+/// mechanism matches what it laid down, then wait for the backend to
+/// respond. Factored out of `auto_setup_on_first_run` so it can be retried
+/// against the next ready source if any step here fails.
+async fn try_install_and_start(
+    window: &tauri::Window,
+    source: &dyn install_source::InstallationSource,
+) -> Result<String, String> {
+    source.install(window).await.map_err(|e| format!("installation failed: {}", e))?;
+    println!("✅ Installation completed, now starting backend...");
+
+    source.start().await.map_err(|e| format!("failed to start backend: {}", e))?;
+    println!("✅ Backend process started, waiting for it to become ready...");
+
+    match wait_for_backend_ready(window.clone(), 60_000).await {
+        Ok(true) => {
+            println!("✅ Backend started after installation");
+            Ok("Installation completed! Backend is now running.".to_string())
+        }
+        Ok(false) => Err("backend did not respond in time".to_string()),
+        Err(e) => Err(format!("failed to confirm backend readiness: {}", e)),
+    }
+}
+
 #[tauri::command]
-async fn auto_setup_on_first_run() -> Result<String, String> {
+async fn auto_setup_on_first_run(window: tauri::Window) -> Result<String, String> {
     println!("🎯 Auto-setup on first run started");
     
     // Check if backend is already installed
     println!("🔍 Checking if backend is already installed...");
     match check_backend_installed().await {
-        Ok(true) => {
+        Ok(status) if status.installed => {
             println!("✅ Backend already installed, ready to use");
             // Backend already installed, frontend will handle starting it
             Ok("Backend is installed and ready. Frontend will start the server.".to_string())
         },
-        Ok(false) => {
-            println!("📦 Backend not installed, starting installation...");
-            // Backend not installed, install it automatically using setup_installer.exe
-            match install_backend().await {
-                Ok(_install_msg) => {
-                    println!("✅ Installation completed, now starting backend with logs...");
-                    // After successful installation, start the backend
-                    match start_backend().await {
-                        Ok(_start_msg) => {
-                            println!("✅ Backend started after installation");
-                            Ok(format!("Installation completed! Backend is now running."))
-                        },
-                        Err(e) => {
-                            println!("❌ Failed to start backend after installation: {}", e);
-                            Err(format!("Installation completed but failed to start backend: {}", e))
-                        }
+        Ok(_) => {
+            println!("📦 Backend not installed, picking an installation source...");
+            let app_data = dirs::data_local_dir()
+                .ok_or("Could not find local app data directory")?;
+            let install_path = app_data.join("VisualPIIStripper");
+            let sources = install_source::ready_sources(&install_path);
+            if sources.is_empty() {
+                return Err("No installation method is available for this platform".to_string());
+            }
+
+            // Try each ready source in turn rather than giving up after the
+            // first - e.g. a discoverable-but-broken system Python shouldn't
+            // stop the standalone runtime download from being attempted.
+            let mut errors = Vec::new();
+            for source in sources {
+                println!("📦 Installing via {}...", source.name());
+                match try_install_and_start(&window, source.as_ref()).await {
+                    Ok(msg) => return Ok(msg),
+                    Err(e) => {
+                        println!("❌ {} failed: {}", source.name(), e);
+                        errors.push(format!("{}: {}", source.name(), e));
                     }
-                },
-                Err(e) => {
-                    println!("❌ Installation failed: {}", e);
-                    Err(format!("Auto-installation failed: {}", e))
                 }
             }
+
+            Err(format!("Auto-installation failed for every available method:\n{}", errors.join("\n")))
         },
         Err(e) => {
             println!("❌ Failed to check backend status: {}", e);
@@ -508,8 +808,14 @@ pub fn run() {
             start_backend_with_logs,
             start_backend_direct,
             check_backend_running,
+            wait_for_backend_ready,
             test_resources,
-            auto_setup_on_first_run
+            auto_setup_on_first_run,
+            runtime::download_python_runtime,
+            interpreter::discover_python_interpreters,
+            install_source::list_install_options,
+            lifecycle::uninstall_backend,
+            lifecycle::upgrade_backend
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,97 @@
+// Uninstall/upgrade lifecycle commands, plus the installed-version marker
+// that `check_backend_installed` reports against.
+
+use std::fs;
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::interpreter;
+use crate::runtime;
+
+/// Bumped whenever the managed backend install (requirements, layout) changes
+/// in a way that requires re-running the installer.
+pub const CURRENT_BACKEND_VERSION: &str = "1.0.0";
+
+const VERSION_MARKER_FILE: &str = "version.txt";
+
+pub fn write_version_marker(install_path: &Path) -> std::io::Result<()> {
+    fs::write(install_path.join(VERSION_MARKER_FILE), CURRENT_BACKEND_VERSION)
+}
+
+pub fn installed_version(install_path: &Path) -> Option<String> {
+    fs::read_to_string(install_path.join(VERSION_MARKER_FILE))
+        .ok()
+        .map(|v| v.trim().to_string())
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BackendInstallStatus {
+    pub installed: bool,
+    pub version: Option<String>,
+    pub upgrade_available: bool,
+}
+
+#[tauri::command]
+pub async fn uninstall_backend(window: tauri::Window) -> Result<String, String> {
+    println!("🗑️  Uninstalling backend...");
+
+    let app_data = dirs::data_local_dir().ok_or("Could not find local app data directory")?;
+    let install_path = app_data.join("VisualPIIStripper");
+
+    if !install_path.exists() {
+        println!("ℹ️  Nothing to uninstall, install path does not exist");
+        return Ok("Backend is not installed, nothing to uninstall.".to_string());
+    }
+
+    crate::stop_running_backend(&install_path);
+
+    crate::remove_dir_recursive_with_progress(&window, &install_path)
+        .map_err(|e| format!("Failed to remove installation: {}", e))?;
+
+    println!("✅ Backend uninstalled");
+    Ok("Backend uninstalled successfully.".to_string())
+}
+
+#[tauri::command]
+pub async fn upgrade_backend() -> Result<String, String> {
+    println!("⬆️  Upgrading backend...");
+
+    let app_data = dirs::data_local_dir().ok_or("Could not find local app data directory")?;
+    let install_path = app_data.join("VisualPIIStripper");
+    let backend_dir = install_path.join("backend");
+    let requirements_path = backend_dir.join("requirements.txt");
+
+    if !requirements_path.exists() {
+        return Err("Backend is not installed; nothing to upgrade.".to_string());
+    }
+
+    // Upgrade in place against whatever interpreter `start_backend_direct`
+    // actually launches the backend with - a separate venv here had nothing
+    // consuming it, so packages installed into it never took effect.
+    let (python_exe, use_user_flag) = interpreter::resolve_interpreter_for_install(&install_path).await?;
+
+    println!("📦 Upgrading Python packages...");
+    let mut pip_args = vec!["-m", "pip", "install", "-r", requirements_path.to_str().unwrap(), "--upgrade"];
+    if use_user_flag {
+        pip_args.push("--user");
+    }
+
+    let pip_upgrade = runtime::hidden_console_command(&python_exe)
+        .args(&pip_args)
+        .output()
+        .map_err(|e| format!("Failed to run pip upgrade: {}", e))?;
+
+    if !pip_upgrade.status.success() {
+        return Err(format!(
+            "Failed to upgrade Python packages:\n{}",
+            String::from_utf8_lossy(&pip_upgrade.stderr)
+        ));
+    }
+
+    write_version_marker(&install_path)
+        .map_err(|e| format!("Failed to write version marker: {}", e))?;
+
+    println!("✅ Backend upgraded to {}", CURRENT_BACKEND_VERSION);
+    Ok(format!("Backend upgraded to version {}", CURRENT_BACKEND_VERSION))
+}
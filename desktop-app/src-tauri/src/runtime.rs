@@ -0,0 +1,264 @@
+// Management of the standalone, self-contained CPython runtime bundled
+// per-install so the app no longer depends on a system Python being present.
+//
+// Archives are the python-build-standalone releases published at
+// https://github.com/indygreg/python-build-standalone/releases
+
+use std::fs::{self, File};
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+
+#[cfg(windows)]
+use std::os::windows::process::CommandExt;
+
+const PYTHON_BUILD_STANDALONE_TAG: &str = "20240107";
+const PYTHON_BUILD_STANDALONE_VERSION: &str = "3.11.7";
+
+/// Directory name, relative to the install path, where the downloaded
+/// runtime is unpacked.
+const RUNTIME_DIR_NAME: &str = "python-runtime";
+
+/// Returns the python-build-standalone target triple for the host this
+/// binary was compiled for, e.g. "x86_64-pc-windows-msvc".
+fn host_triple() -> Result<&'static str, String> {
+    #[cfg(all(target_os = "windows", target_arch = "x86_64"))]
+    return Ok("x86_64-pc-windows-msvc");
+
+    #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
+    return Ok("aarch64-apple-darwin");
+
+    #[cfg(all(target_os = "macos", target_arch = "x86_64"))]
+    return Ok("x86_64-apple-darwin");
+
+    #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+    return Ok("x86_64-unknown-linux-gnu");
+
+    #[cfg(all(target_os = "linux", target_arch = "aarch64"))]
+    return Ok("aarch64-unknown-linux-gnu");
+
+    #[cfg(not(any(
+        all(target_os = "windows", target_arch = "x86_64"),
+        all(target_os = "macos", target_arch = "aarch64"),
+        all(target_os = "macos", target_arch = "x86_64"),
+        all(target_os = "linux", target_arch = "x86_64"),
+        all(target_os = "linux", target_arch = "aarch64"),
+    )))]
+    return Err("No standalone Python build is published for this platform/architecture".to_string());
+}
+
+/// Archive variant a python-build-standalone release asset can ship as.
+/// Which one is actually published varies by triple and by release, so
+/// callers try `candidate_formats_for` in order rather than assuming one.
+enum ArchiveFormat {
+    TarZstd,
+    TarGzip,
+    Zip,
+}
+
+/// Archive formats to try for a given triple, most-likely-published first.
+/// The `install_only` asset python-build-standalone publishes is a `.tar.gz`
+/// for non-Windows triples and a `.zip` for Windows; `.tar.zst` is kept as a
+/// fallback since some releases have shipped zstd archives instead.
+fn candidate_formats_for(triple: &str) -> Vec<ArchiveFormat> {
+    if triple.contains("windows") {
+        vec![ArchiveFormat::Zip, ArchiveFormat::TarGzip]
+    } else {
+        vec![ArchiveFormat::TarGzip, ArchiveFormat::TarZstd]
+    }
+}
+
+fn archive_extension(format: &ArchiveFormat) -> &'static str {
+    match format {
+        ArchiveFormat::TarZstd => "tar.zst",
+        ArchiveFormat::TarGzip => "tar.gz",
+        ArchiveFormat::Zip => "zip",
+    }
+}
+
+fn download_url(triple: &str, format: &ArchiveFormat) -> String {
+    format!(
+        "https://github.com/indygreg/python-build-standalone/releases/download/{tag}/cpython-{version}+{tag}-{triple}-install_only.{ext}",
+        tag = PYTHON_BUILD_STANDALONE_TAG,
+        version = PYTHON_BUILD_STANDALONE_VERSION,
+        triple = triple,
+        ext = archive_extension(format),
+    )
+}
+
+/// Path to the interpreter inside an already-extracted runtime directory.
+pub fn interpreter_path(install_path: &Path) -> PathBuf {
+    let runtime_dir = install_path.join(RUNTIME_DIR_NAME);
+    if cfg!(windows) {
+        runtime_dir.join("python.exe")
+    } else {
+        runtime_dir.join("bin").join("python3")
+    }
+}
+
+pub fn runtime_installed(install_path: &Path) -> bool {
+    interpreter_path(install_path).exists()
+}
+
+fn extract_archive(archive_path: &Path, format: &ArchiveFormat, dest: &Path) -> Result<(), String> {
+    fs::create_dir_all(dest).map_err(|e| format!("Failed to create runtime directory: {}", e))?;
+
+    match format {
+        ArchiveFormat::Zip => {
+            let file = File::open(archive_path)
+                .map_err(|e| format!("Failed to open downloaded archive: {}", e))?;
+            let mut archive = zip::ZipArchive::new(BufReader::new(file))
+                .map_err(|e| format!("Failed to read zip archive: {}", e))?;
+            archive
+                .extract(dest)
+                .map_err(|e| format!("Failed to extract zip archive: {}", e))?;
+        }
+        ArchiveFormat::TarGzip => {
+            let file = File::open(archive_path)
+                .map_err(|e| format!("Failed to open downloaded archive: {}", e))?;
+            let decoder = flate2::read::GzDecoder::new(BufReader::new(file));
+            let mut archive = tar::Archive::new(decoder);
+            archive
+                .unpack(dest)
+                .map_err(|e| format!("Failed to extract tar.gz archive: {}", e))?;
+        }
+        ArchiveFormat::TarZstd => {
+            let file = File::open(archive_path)
+                .map_err(|e| format!("Failed to open downloaded archive: {}", e))?;
+            let decoder = zstd::stream::read::Decoder::new(BufReader::new(file))
+                .map_err(|e| format!("Failed to init zstd decoder: {}", e))?;
+            let mut archive = tar::Archive::new(decoder);
+            archive
+                .unpack(dest)
+                .map_err(|e| format!("Failed to extract tar.zst archive: {}", e))?;
+        }
+    }
+
+    // python-build-standalone archives unpack under a top-level "python/"
+    // directory; flatten it into the runtime directory we expose.
+    let nested = dest.join("python");
+    if nested.is_dir() {
+        for entry in fs::read_dir(&nested)
+            .map_err(|e| format!("Failed to read extracted archive: {}", e))?
+        {
+            let entry = entry.map_err(|e| format!("Failed to read archive entry: {}", e))?;
+            let target = dest.join(entry.file_name());
+            fs::rename(entry.path(), &target)
+                .map_err(|e| format!("Failed to move extracted file: {}", e))?;
+        }
+        fs::remove_dir_all(&nested).ok();
+    }
+
+    Ok(())
+}
+
+/// Downloads the first candidate archive format that actually exists for
+/// `triple`, returning its bytes alongside the format used.
+async fn fetch_runtime_archive(triple: &str) -> Result<(Vec<u8>, ArchiveFormat), String> {
+    let mut last_error = String::new();
+
+    for format in candidate_formats_for(triple) {
+        let url = download_url(triple, &format);
+        println!("⬇️  Trying {}", url);
+
+        match reqwest::get(&url).await {
+            Ok(response) if response.status().is_success() => {
+                let bytes = response
+                    .bytes()
+                    .await
+                    .map_err(|e| format!("Failed to read downloaded archive: {}", e))?;
+                return Ok((bytes.to_vec(), format));
+            }
+            Ok(response) => {
+                last_error = format!("server returned {} for {}", response.status(), url);
+            }
+            Err(e) => {
+                last_error = format!("{}", e);
+            }
+        }
+    }
+
+    Err(format!(
+        "Failed to download Python runtime for {}: {}",
+        triple, last_error
+    ))
+}
+
+#[tauri::command]
+pub async fn download_python_runtime() -> Result<String, String> {
+    println!("🐍 Downloading standalone Python runtime...");
+
+    let triple = host_triple()?;
+
+    let app_data = dirs::data_local_dir().ok_or("Could not find local app data directory")?;
+    let install_path = app_data.join("VisualPIIStripper");
+    let runtime_dir = install_path.join(RUNTIME_DIR_NAME);
+
+    if runtime_installed(&install_path) {
+        println!("✅ Python runtime already present at {:?}", runtime_dir);
+        return Ok(format!("Python runtime already installed at {:?}", runtime_dir));
+    }
+
+    let (bytes, format) = fetch_runtime_archive(triple).await?;
+
+    fs::create_dir_all(&install_path)
+        .map_err(|e| format!("Failed to create install directory: {}", e))?;
+    let archive_path = install_path.join(format!("python-runtime.{}", archive_extension(&format)));
+    fs::write(&archive_path, &bytes)
+        .map_err(|e| format!("Failed to write downloaded archive: {}", e))?;
+
+    println!("📦 Extracting Python runtime to {:?}", runtime_dir);
+    extract_archive(&archive_path, &format, &runtime_dir)?;
+    fs::remove_file(&archive_path).ok();
+
+    let python_exe = interpreter_path(&install_path);
+    if !python_exe.exists() {
+        return Err(format!(
+            "Python runtime extracted but interpreter not found at {:?}",
+            python_exe
+        ));
+    }
+
+    println!("✅ Python runtime ready at {:?}", python_exe);
+    Ok(format!("Python runtime installed at {:?}", python_exe))
+}
+
+/// Builds a `Command` for the given interpreter with the console window
+/// hidden on Windows, matching the rest of the backend process helpers.
+pub fn hidden_console_command(program: &Path) -> std::process::Command {
+    let mut cmd = std::process::Command::new(program);
+    #[cfg(windows)]
+    cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW flag
+    cmd
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn windows_triples_try_zip_before_tar_gzip() {
+        let formats = candidate_formats_for("x86_64-pc-windows-msvc");
+        assert!(matches!(formats[0], ArchiveFormat::Zip));
+        assert!(matches!(formats[1], ArchiveFormat::TarGzip));
+    }
+
+    #[test]
+    fn non_windows_triples_try_tar_gzip_before_tar_zstd() {
+        let formats = candidate_formats_for("x86_64-unknown-linux-gnu");
+        assert!(matches!(formats[0], ArchiveFormat::TarGzip));
+        assert!(matches!(formats[1], ArchiveFormat::TarZstd));
+
+        let formats = candidate_formats_for("aarch64-apple-darwin");
+        assert!(matches!(formats[0], ArchiveFormat::TarGzip));
+        assert!(matches!(formats[1], ArchiveFormat::TarZstd));
+    }
+
+    #[test]
+    fn download_url_uses_the_tried_format_extension() {
+        let url = download_url("x86_64-unknown-linux-gnu", &ArchiveFormat::TarGzip);
+        assert!(url.ends_with(".tar.gz"));
+
+        let url = download_url("x86_64-pc-windows-msvc", &ArchiveFormat::Zip);
+        assert!(url.ends_with(".zip"));
+    }
+}